@@ -194,8 +194,11 @@ fn create_window_adapter(
                     .map_err(|e| format!("Failed to parse SLINT_KMS_ROTATION: {e}"))
             })?;
 
+        let min_frame_interval = std::env::var("SLINT_KMS_MAX_FPS")
+            .map_or(Ok(None), |fps_str| parse_max_fps(&fps_str).map(Some))?;
+
         let renderer = (self.renderer_factory)(&device_accessor)?;
-        let adapter = FullscreenWindowAdapter::new(renderer, rotation)?;
+        let adapter = FullscreenWindowAdapter::new(renderer, rotation, min_frame_interval)?;
 
         *self.window.borrow_mut() = Some(adapter.clone());
 
@@ -297,3 +300,25 @@ fn set_clipboard_text(&self, text: &str, clipboard: i_slint_core::platform::Clip
 
 #[derive(Default)]
 pub struct LoopData {}
+
+/// Parses the value of `SLINT_KMS_MAX_FPS` into the minimum interval between two frames.
+fn parse_max_fps(fps_str: &str) -> Result<std::time::Duration, String> {
+    let error =
+        || format!("Failed to parse SLINT_KMS_MAX_FPS: Must be a positive number, found {fps_str}");
+    match fps_str.parse::<f64>() {
+        Ok(fps) if fps.is_finite() && fps > 0. => {
+            std::time::Duration::try_from_secs_f64(1. / fps).map_err(|_| error())
+        }
+        _ => Err(error()),
+    }
+}
+
+#[test]
+fn test_parse_max_fps() {
+    assert_eq!(parse_max_fps("30"), Ok(std::time::Duration::from_secs_f64(1. / 30.)));
+    assert!(parse_max_fps("0").is_err());
+    assert!(parse_max_fps("-5").is_err());
+    assert!(parse_max_fps("abc").is_err());
+    assert!(parse_max_fps("inf").is_err());
+    assert!(parse_max_fps("1e-30").is_err());
+}
@@ -6,14 +6,16 @@
 use std::cell::Cell;
 use std::pin::Pin;
 use std::rc::Rc;
+use std::time::{Duration, Instant};
 
 use i_slint_core::Property;
 use i_slint_core::api::{LogicalPosition, PhysicalSize as PhysicalWindowSize};
-use i_slint_core::graphics::{Image, euclid};
+use i_slint_core::graphics::Image;
 use i_slint_core::item_rendering::ItemRenderer;
-use i_slint_core::lengths::LogicalRect;
+use i_slint_core::lengths::{LogicalPoint, LogicalRect, LogicalSize, LogicalVector};
 use i_slint_core::platform::WindowEvent;
 use i_slint_core::slice::Slice;
+use i_slint_core::timers::{Timer, TimerMode};
 use i_slint_core::{platform::PlatformError, window::WindowAdapter};
 
 use crate::display::RenderingRotation;
@@ -33,6 +35,10 @@ pub struct FullscreenWindowAdapter {
     renderer: Box<dyn FullscreenRenderer>,
     redraw_requested: Cell<bool>,
     rotation: RenderingRotation,
+    /// When set, frames are not presented more often than once per this interval.
+    min_frame_interval: Option<Duration>,
+    last_frame_presented: Cell<Option<Instant>>,
+    frame_throttle_timer: Timer,
 }
 
 impl WindowAdapter for FullscreenWindowAdapter {
@@ -67,6 +73,7 @@ impl FullscreenWindowAdapter {
     pub fn new(
         renderer: Box<dyn FullscreenRenderer>,
         rotation: RenderingRotation,
+        min_frame_interval: Option<Duration>,
     ) -> Result<Rc<Self>, PlatformError> {
         let size = renderer.size();
         let rotation_degrees = rotation.degrees();
@@ -85,6 +92,9 @@ pub fn new(
             renderer,
             redraw_requested: Cell::new(true),
             rotation,
+            min_frame_interval,
+            last_frame_presented: Cell::new(None),
+            frame_throttle_timer: Default::default(),
         }))
     }
 
@@ -92,23 +102,45 @@ pub fn render_if_needed(
         self: Rc<Self>,
         mouse_position: Pin<&Property<Option<LogicalPosition>>>,
     ) -> Result<(), PlatformError> {
+        if self.redraw_requested.get()
+            && let Some(min_frame_interval) = self.min_frame_interval
+            && let Some(last_frame_presented) = self.last_frame_presented.get()
+        {
+            let elapsed = last_frame_presented.elapsed();
+            if elapsed < min_frame_interval {
+                // Keep the redraw pending and wake up the event loop once the frame interval has passed.
+                if !self.frame_throttle_timer.running() {
+                    let self_weak = Rc::downgrade(&self);
+                    self.frame_throttle_timer.start(
+                        TimerMode::SingleShot,
+                        min_frame_interval - elapsed,
+                        move || {
+                            if let Some(this) = self_weak.upgrade() {
+                                this.request_redraw();
+                            }
+                        },
+                    );
+                }
+                return Ok(());
+            }
+        }
+
         if self.redraw_requested.replace(false) {
+            // This frame satisfies any redraw that was deferred by the frame rate limit.
+            self.frame_throttle_timer.stop();
             self.renderer.render_and_present(self.rotation, &|item_renderer| {
                 if let Some(mouse_position) = mouse_position.get() {
-                    let cursor_image = mouse_cursor_image();
+                    let cursor = mouse_cursor();
+                    let cursor_rect = cursor
+                        .rect_at(i_slint_core::lengths::logical_point_from_api(mouse_position));
                     item_renderer.save_state();
-                    item_renderer.translate(
-                        i_slint_core::lengths::logical_point_from_api(mouse_position).to_vector(),
-                    );
-                    item_renderer.draw_image_direct(mouse_cursor_image());
+                    item_renderer.translate(cursor_rect.origin.to_vector());
+                    item_renderer.draw_image_direct(cursor.image);
                     item_renderer.restore_state();
-                    let cursor_rect = LogicalRect::new(
-                        euclid::point2(mouse_position.x, mouse_position.y),
-                        euclid::Size2D::from_untyped(cursor_image.size().cast()),
-                    );
                     self.renderer.as_core_renderer().mark_dirty_region(cursor_rect.into());
                 }
             })?;
+            self.last_frame_presented.set(Some(Instant::now()));
             // Check once after rendering if we have running animations and
             // remember that to trigger a redraw after the frame is on the screen.
             // Timers might have been updated if the event loop is woken up
@@ -130,6 +162,29 @@ pub fn render_if_needed(
     }
 }
 
+struct MouseCursor {
+    image: Image,
+    /// The point in the image, in logical pixels relative to its top-left corner, that
+    /// is placed under the pointer position.
+    hotspot: LogicalVector,
+}
+
+impl MouseCursor {
+    /// Returns the area covered by the cursor image when its hotspot is placed at `pointer_position`.
+    fn rect_at(&self, pointer_position: LogicalPoint) -> LogicalRect {
+        LogicalRect::new(
+            pointer_position - self.hotspot,
+            LogicalSize::from_untyped(self.image.size().cast()),
+        )
+    }
+}
+
+fn mouse_cursor() -> MouseCursor {
+    // mouse-pointer.svg is 16x24 with a square viewBox, so the arrow is centered vertically
+    // and its tip is three pixels below the top edge of the image.
+    MouseCursor { image: mouse_cursor_image(), hotspot: LogicalVector::new(0., 3.) }
+}
+
 fn mouse_cursor_image() -> Image {
     let mouse_pointer_svg = i_slint_core::graphics::load_image_from_embedded_data(
         Slice::from_slice(include_bytes!("mouse-pointer.svg")),
@@ -154,3 +209,24 @@ fn mouse_cursor_image() -> Image {
         cached_image => cached_image.clone().into(),
     }
 }
+
+#[test]
+fn test_builtin_mouse_cursor_hotspot() {
+    let cursor = mouse_cursor();
+
+    // The hotspot must be the top-left corner of the arrow's visible pixels.
+    let pixels = cursor.image.to_rgba8().unwrap();
+    let width = pixels.width() as usize;
+    let (mut min_x, mut min_y) = (usize::MAX, usize::MAX);
+    for (index, pixel) in pixels.as_slice().iter().enumerate() {
+        if pixel.a != 0 {
+            min_x = min_x.min(index % width);
+            min_y = min_y.min(index / width);
+        }
+    }
+    assert_eq!(cursor.hotspot, LogicalVector::new(min_x as f32, min_y as f32));
+
+    let rect = cursor.rect_at(LogicalPoint::new(100., 50.));
+    assert_eq!(rect.origin, LogicalPoint::new(100., 47.));
+    assert_eq!(rect.size, LogicalSize::new(16., 24.));
+}